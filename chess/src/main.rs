@@ -1,19 +1,49 @@
 use std::path::Path;
 use sdl2::event::Event;
+use sdl2::image::LoadTexture;
 use sdl2::pixels::Color;
 use sdl2::render::{Texture, TextureCreator, WindowCanvas};
 use sdl2::EventPump;
 use sdl2::rect::Rect;
-use sdl2::surface::Surface;
 use sdl2::video::WindowContext;
 
-const WINDOW_SIZE: u32 = 800;
 const BOARD_SIZE: i32 = 8;
-const TILE_SIZE: i32 = 100;
-const PIECE_SIZE: i32 = 90;
-const PIECE_OFFSET: i32 = 5;
 
-#[derive(PartialEq)]
+const DEFAULT_WINDOW_SIZE: u32 = 800;
+const DEFAULT_TITLE: &str = "Chess";
+
+const PIECE_ATLAS_PATH: &str = "assets/pieces.png";
+// Atlas is laid out as 6 columns (one per PieceType) by 2 rows (black, white),
+// each cell a square sprite.
+const ATLAS_COLS: i32 = 6;
+const ATLAS_ROWS: i32 = 2;
+const ATLAS_CELL_SIZE: i32 = 333;
+
+const NS_PER_FRAME: u64 = 1_000_000_000 / 60;
+// Caps how much simulation time a single stalled frame (window drag, minimize, a
+// breakpoint) can owe `update`, so catching up never spirals into a frame that
+// never finishes.
+const MAX_ACCUMULATOR_NS: u64 = NS_PER_FRAME * 5;
+
+#[derive(PartialEq, Clone, Copy)]
+enum PlayerKind { Human, Ai }
+
+impl PlayerKind {
+    fn toggled(&self) -> Self {
+        match self {
+            PlayerKind::Human => PlayerKind::Ai,
+            PlayerKind::Ai => PlayerKind::Human,
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+enum GameState { MainMenu, Playing, Paused, GameOver }
+
+#[derive(Clone, Copy)]
+enum GameResult { WhiteWins, BlackWins, Stalemate }
+
+#[derive(PartialEq, Clone, Copy)]
 enum PieceType {
     Pawn,
     Queen,
@@ -23,39 +53,63 @@ enum PieceType {
     King
 }
 
-#[derive(PartialEq)]
+impl PieceType {
+    /// Column of this piece's sprite within the atlas.
+    fn atlas_col(&self) -> i32 {
+        match self {
+            PieceType::Pawn => 0,
+            PieceType::Queen => 1,
+            PieceType::Rook => 2,
+            PieceType::Knight => 3,
+            PieceType::Bishop => 4,
+            PieceType::King => 5,
+        }
+    }
+}
+
+#[derive(PartialEq, Clone, Copy)]
 enum PieceColor { Black, White }
 
-struct Pawn<'a> {
-    texture: Texture<'a>,
-    rect: Rect,
-    row: i32,
-    col: i32,
-    is_selected: bool,
-    has_moved: bool,
-    color: PieceColor,
-    piece_type: PieceType,
-}
+impl PieceColor {
+    /// Row of this color's sprites within the atlas.
+    fn atlas_row(&self) -> i32 {
+        match self {
+            PieceColor::Black => 0,
+            PieceColor::White => 1,
+        }
+    }
 
-impl Pawn<'_> {
-    fn new(texture_creator: &TextureCreator<WindowContext>, is_white: bool, row: i32, col: i32) -> Self {
-        let surface_path = if is_white { "assets/white_pawn.bmp" } else { "assets/black_pawn.bmp" };
-        let surface = Surface::load_bmp(Path::new(surface_path)).expect("Failed to create surface");
+    fn opposite(&self) -> Self {
+        match self {
+            PieceColor::Black => PieceColor::White,
+            PieceColor::White => PieceColor::Black,
+        }
+    }
+}
 
-        let texture = texture_creator.create_texture_from_surface(surface).expect("Failed to create texture");
-        let rect = Rect::new(col * TILE_SIZE, row * TILE_SIZE, PIECE_SIZE as u32, PIECE_SIZE as u32);
+/// Looks up the source rect of a piece's sprite within the shared atlas texture.
+fn piece_src_rect(piece_type: &PieceType, color: &PieceColor) -> Rect {
+    Rect::new(
+        piece_type.atlas_col() * ATLAS_CELL_SIZE,
+        color.atlas_row() * ATLAS_CELL_SIZE,
+        ATLAS_CELL_SIZE as u32,
+        ATLAS_CELL_SIZE as u32,
+    )
+}
 
-        let color = if is_white { PieceColor::White } else { PieceColor::Black };
+#[derive(Clone, Copy)]
+struct Piece {
+    piece_type: PieceType,
+    color: PieceColor,
+    has_moved: bool,
+}
 
-        Pawn {
-            texture,
-            rect,
-            row,
-            col,
-            is_selected: false,
-            has_moved: false,
+impl Piece {
+    fn new(piece_type: PieceType, color: PieceColor) -> Self {
+        Piece {
+            piece_type,
             color,
-            piece_type: PieceType::Pawn,
+            has_moved: false,
         }
     }
 }
@@ -66,25 +120,38 @@ enum TileColor { Black, White }
 struct Tile {
     rect: Rect,
     tile_color: TileColor,
-    is_occupied: bool,
     is_highlighted: bool
 }
 
 struct Board<'a> {
     tiles: Vec<Vec<Tile>>,
-    pawns: Vec<Pawn<'a>>
+    squares: Vec<Vec<Option<Piece>>>,
+    piece_atlas: Texture<'a>,
+    board_texture: Texture<'a>,
+    /// Set whenever tile highlight state changes, so `render` knows to redraw
+    /// `board_texture` before blitting it instead of refilling every tile every frame.
+    dirty: bool,
+    side_to_move: PieceColor,
+    selected: Option<(usize, usize)>,
+    en_passant_target: Option<(usize, usize)>,
+    tile_size: i32,
+    piece_size: i32,
+    piece_offset: i32,
 }
 
-impl Board<'_> {
-    fn new(texture_creator: &TextureCreator<WindowContext>) -> Self {
+impl<'a> Board<'a> {
+    fn new(texture_creator: &'a TextureCreator<WindowContext>, tile_size: i32) -> Self {
+        // The piece sprite leaves a small margin on each side of its tile.
+        let piece_offset = tile_size / 20;
+        let piece_size = tile_size - piece_offset * 2;
+
         let mut tiles: Vec<Vec<Tile>> = vec!();
         for row in 0..BOARD_SIZE {
             let mut tile_row: Vec<Tile> = vec!();
             for col in 0..BOARD_SIZE {
                 let tile: Tile = Tile {
-                    rect: Rect::new(col * TILE_SIZE, row * TILE_SIZE, TILE_SIZE as u32, TILE_SIZE as u32),
+                    rect: Rect::new(col * tile_size, row * tile_size, tile_size as u32, tile_size as u32),
                     tile_color: if (row + col) % 2 == 0 { TileColor::White } else { TileColor::Black },
-                    is_occupied: false,
                     is_highlighted: false
                 };
 
@@ -93,49 +160,121 @@ impl Board<'_> {
             tiles.push(tile_row);
         }
 
-        let mut pawns: Vec<Pawn> = vec!();
-        for col in 0..BOARD_SIZE {
-            pawns.push(Pawn::new(texture_creator, false, 7, col))
+        let mut squares: Vec<Vec<Option<Piece>>> = vec![vec![None; BOARD_SIZE as usize]; BOARD_SIZE as usize];
+        Self::setup_back_rank(&mut squares, 0, PieceColor::Black);
+        Self::setup_back_rank(&mut squares, 7, PieceColor::White);
+        for square in squares[1].iter_mut() {
+            *square = Some(Piece::new(PieceType::Pawn, PieceColor::Black));
         }
-        
-        Board {
+        for square in squares[6].iter_mut() {
+            *square = Some(Piece::new(PieceType::Pawn, PieceColor::White));
+        }
+
+        let board_size = (tile_size * BOARD_SIZE) as u32;
+        let board_texture = texture_creator.create_texture_target(None, board_size, board_size)
+            .expect("Failed to create board render target");
+
+        let board = Board {
             tiles,
-            pawns
+            squares,
+            piece_atlas: texture_creator.load_texture(Path::new(PIECE_ATLAS_PATH))
+                .expect("Failed to load piece atlas"),
+            board_texture,
+            dirty: true,
+            side_to_move: PieceColor::White,
+            selected: None,
+            en_passant_target: None,
+            tile_size,
+            piece_size,
+            piece_offset,
+        };
+
+        let atlas_query = board.piece_atlas.query();
+        assert_eq!(atlas_query.width, (ATLAS_COLS * ATLAS_CELL_SIZE) as u32, "piece atlas width mismatch");
+        assert_eq!(atlas_query.height, (ATLAS_ROWS * ATLAS_CELL_SIZE) as u32, "piece atlas height mismatch");
+
+        board
+    }
+
+    fn setup_back_rank(squares: &mut [Vec<Option<Piece>>], row: usize, color: PieceColor) {
+        const BACK_RANK: [PieceType; 8] = [
+            PieceType::Rook, PieceType::Knight, PieceType::Bishop, PieceType::Queen,
+            PieceType::King, PieceType::Bishop, PieceType::Knight, PieceType::Rook,
+        ];
+        for (col, piece_type) in BACK_RANK.into_iter().enumerate() {
+            squares[row][col] = Some(Piece::new(piece_type, color));
         }
     }
 
     fn render(&mut self, canvas: &mut WindowCanvas) {
+        if self.dirty {
+            let tiles = &self.tiles;
+            canvas.with_texture_canvas(&mut self.board_texture, |texture_canvas| {
+                for tile_row in tiles.iter() {
+                    for tile in tile_row.iter() {
+                        if !tile.is_highlighted {
+                            if tile.tile_color == TileColor::White {
+                                texture_canvas.set_draw_color(Color::RGB(234, 221, 202));
+                            } else {
+                                texture_canvas.set_draw_color(Color::RGB(111, 78, 55));
+                            }
+                        } else {
+                            if tile.tile_color == TileColor::White {
+                                texture_canvas.set_draw_color(Color::RGB(137, 196, 244));
+                            } else {
+                                texture_canvas.set_draw_color(Color::RGB(112, 169, 215));
+                            }
+                        }
+                        texture_canvas.fill_rect(tile.rect).expect("Failed to fill rect");
+                    }
+                }
+            }).expect("Failed to render board texture");
+            self.dirty = false;
+        }
+        let board_size = (self.tile_size * BOARD_SIZE) as u32;
+        canvas.copy(&self.board_texture, None, Some(Rect::new(0, 0, board_size, board_size)))
+            .expect("Failed to copy board texture");
+
         for row in 0..BOARD_SIZE as usize {
             for col in 0..BOARD_SIZE as usize {
-                if !self.tiles[row][col].is_highlighted {
-                    if self.tiles[row][col].tile_color == TileColor::White {
-                        canvas.set_draw_color(Color::RGB(234, 221, 202));
-                    } else {
-                        canvas.set_draw_color(Color::RGB(111, 78, 55));
-                    }
-                } else {
-                    if self.tiles[row][col].tile_color == TileColor::White {
-                        canvas.set_draw_color(Color::RGB(137, 196, 244));
-                    } else {
-                        canvas.set_draw_color(Color::RGB(112, 169, 215));
-                    }
+                if let Some(piece) = self.squares[row][col] {
+                    let src_rect = piece_src_rect(&piece.piece_type, &piece.color);
+                    let dst_rect = Rect::new(
+                        col as i32 * self.tile_size + self.piece_offset,
+                        row as i32 * self.tile_size + self.piece_offset,
+                        self.piece_size as u32,
+                        self.piece_size as u32,
+                    );
+                    canvas.copy(&self.piece_atlas, Some(src_rect), Some(dst_rect)).expect("Failed to copy piece texture");
                 }
-                canvas.fill_rect(self.tiles[row][col].rect).expect("Failed to fill rect");
             }
         }
     }
 
     fn handle_click(&mut self, mouse_x: i32, mouse_y: i32) {
-        let row = (mouse_x / TILE_SIZE) as usize;
-        let col = (mouse_y / TILE_SIZE) as usize;
-
-        if self.valid_move(row, col) {
-            if !self.tiles[row][col].is_highlighted {
-                self.clear_selections();
-                self.clear_highlights();
-                if self.tiles[row][col].is_occupied {
-                    // TODO: FINISH THIS
+        let col = (mouse_x / self.tile_size) as usize;
+        let row = (mouse_y / self.tile_size) as usize;
+
+        if !self.valid_move(row, col) {
+            return;
+        }
+
+        if let Some(from) = self.selected {
+            if self.tiles[row][col].is_highlighted {
+                self.make_move(from, (row, col));
+            }
+            self.clear_selections();
+            self.clear_highlights();
+            return;
+        }
+
+        if let Some(piece) = self.squares[row][col] {
+            if piece.color == self.side_to_move {
+                self.selected = Some((row, col));
+                for (target_row, target_col) in self.legal_moves_from(row, col) {
+                    self.tiles[target_row][target_col].is_highlighted = true;
                 }
+                self.dirty = true;
             }
         }
     }
@@ -145,7 +284,7 @@ impl Board<'_> {
     }
 
     fn clear_selections(&mut self) {
-
+        self.selected = None;
     }
 
     fn clear_highlights(&mut self) {
@@ -154,18 +293,200 @@ impl Board<'_> {
                 tile.is_highlighted = false;
             }
         }
+        self.dirty = true;
+    }
+
+    fn make_move(&mut self, from: (usize, usize), to: (usize, usize)) {
+        let (from_row, from_col) = from;
+        let (to_row, to_col) = to;
+        let mut piece = self.squares[from_row][from_col].take().expect("make_move called with no piece at source");
+
+        // En-passant capture: the pawn taken sits beside the destination, not on it.
+        if piece.piece_type == PieceType::Pawn && Some(to) == self.en_passant_target && self.squares[to_row][to_col].is_none() {
+            self.squares[from_row][to_col] = None;
+        }
+
+        self.en_passant_target = if piece.piece_type == PieceType::Pawn && from_row.abs_diff(to_row) == 2 {
+            Some(((from_row + to_row) / 2, from_col))
+        } else {
+            None
+        };
+
+        piece.has_moved = true;
+        self.squares[to_row][to_col] = Some(piece);
+        self.side_to_move = self.side_to_move.opposite();
+    }
+
+    /// Legal destinations for the piece at (row, col): pseudo-legal moves with any
+    /// that would leave the mover's own king in check filtered out.
+    fn legal_moves_from(&self, row: usize, col: usize) -> Vec<(usize, usize)> {
+        let Some(piece) = self.squares[row][col] else { return vec![] };
+        self.pseudo_legal_moves(row, col)
+            .into_iter()
+            .filter(|&(to_row, to_col)| {
+                let mut after = self.clone_squares();
+                // Mirror make_move's en-passant removal: capturing en passant leaves the
+                // taken pawn's square empty, which an along-the-rank pin needs to see too.
+                if piece.piece_type == PieceType::Pawn
+                    && Some((to_row, to_col)) == self.en_passant_target
+                    && after[to_row][to_col].is_none()
+                {
+                    after[row][to_col] = None;
+                }
+                after[to_row][to_col] = after[row][col].take();
+                !Self::is_attacked(&after, Self::find_king(&after, piece.color), piece.color.opposite())
+            })
+            .collect()
+    }
+
+    fn clone_squares(&self) -> Vec<Vec<Option<Piece>>> {
+        self.squares.clone()
+    }
+
+    /// Whether `color`'s king currently sits on an attacked square.
+    fn in_check(&self, color: PieceColor) -> bool {
+        Self::is_attacked(&self.squares, Self::find_king(&self.squares, color), color.opposite())
+    }
+
+    /// Whether `color` has no legal move anywhere on the board: checkmate if
+    /// `color` is also in check, stalemate otherwise.
+    fn has_no_legal_moves(&self, color: PieceColor) -> bool {
+        for row in 0..BOARD_SIZE as usize {
+            for col in 0..BOARD_SIZE as usize {
+                if self.squares[row][col].is_some_and(|piece| piece.color == color) && !self.legal_moves_from(row, col).is_empty() {
+                    return false;
+                }
+            }
+        }
+        true
+    }
+
+    fn find_king(squares: &[Vec<Option<Piece>>], color: PieceColor) -> (usize, usize) {
+        for (row, tile_row) in squares.iter().enumerate() {
+            for (col, square) in tile_row.iter().enumerate() {
+                if let Some(piece) = square {
+                    if piece.piece_type == PieceType::King && piece.color == color {
+                        return (row, col);
+                    }
+                }
+            }
+        }
+        unreachable!("a king is always on the board")
+    }
+
+    /// Whether `target` is attacked by any piece of `by_color` on `squares`.
+    fn is_attacked(squares: &[Vec<Option<Piece>>], target: (usize, usize), by_color: PieceColor) -> bool {
+        for row in 0..BOARD_SIZE as usize {
+            for col in 0..BOARD_SIZE as usize {
+                if let Some(piece) = squares[row][col] {
+                    if piece.color == by_color && Self::pseudo_legal_moves_on(squares, row, col, None).contains(&target) {
+                        return true;
+                    }
+                }
+            }
+        }
+        false
+    }
+
+    fn pseudo_legal_moves(&self, row: usize, col: usize) -> Vec<(usize, usize)> {
+        Self::pseudo_legal_moves_on(&self.squares, row, col, self.en_passant_target)
+    }
+
+    /// Pseudo-legal targets for the piece at (row, col), ignoring whether the move
+    /// would leave the mover's own king in check.
+    fn pseudo_legal_moves_on(squares: &[Vec<Option<Piece>>], row: usize, col: usize, en_passant_target: Option<(usize, usize)>) -> Vec<(usize, usize)> {
+        let Some(piece) = squares[row][col] else { return vec![] };
+        let mut moves = vec![];
+
+        let in_bounds = |r: i32, c: i32| (0..BOARD_SIZE).contains(&r) && (0..BOARD_SIZE).contains(&c);
+        let occupant = |r: i32, c: i32| squares[r as usize][c as usize];
+
+        match piece.piece_type {
+            PieceType::Rook | PieceType::Bishop | PieceType::Queen => {
+                let directions: &[(i32, i32)] = match piece.piece_type {
+                    PieceType::Rook => &[(-1, 0), (1, 0), (0, -1), (0, 1)],
+                    PieceType::Bishop => &[(-1, -1), (-1, 1), (1, -1), (1, 1)],
+                    _ => &[(-1, 0), (1, 0), (0, -1), (0, 1), (-1, -1), (-1, 1), (1, -1), (1, 1)],
+                };
+                for &(dr, dc) in directions {
+                    let (mut r, mut c) = (row as i32 + dr, col as i32 + dc);
+                    while in_bounds(r, c) {
+                        match occupant(r, c) {
+                            None => moves.push((r as usize, c as usize)),
+                            Some(blocker) => {
+                                if blocker.color != piece.color {
+                                    moves.push((r as usize, c as usize));
+                                }
+                                break;
+                            }
+                        }
+                        r += dr;
+                        c += dc;
+                    }
+                }
+            }
+            PieceType::Knight => {
+                const OFFSETS: [(i32, i32); 8] = [
+                    (-2, -1), (-2, 1), (-1, -2), (-1, 2), (1, -2), (1, 2), (2, -1), (2, 1),
+                ];
+                for (dr, dc) in OFFSETS {
+                    let (r, c) = (row as i32 + dr, col as i32 + dc);
+                    if in_bounds(r, c) && occupant(r, c).is_none_or(|blocker| blocker.color != piece.color) {
+                        moves.push((r as usize, c as usize));
+                    }
+                }
+            }
+            PieceType::King => {
+                for dr in -1..=1 {
+                    for dc in -1..=1 {
+                        if dr == 0 && dc == 0 { continue; }
+                        let (r, c) = (row as i32 + dr, col as i32 + dc);
+                        if in_bounds(r, c) && occupant(r, c).is_none_or(|blocker| blocker.color != piece.color) {
+                            moves.push((r as usize, c as usize));
+                        }
+                    }
+                }
+            }
+            PieceType::Pawn => {
+                let forward = if piece.color == PieceColor::White { -1 } else { 1 };
+                let start_row = if piece.color == PieceColor::White { 6 } else { 1 };
+
+                let single = row as i32 + forward;
+                if in_bounds(single, col as i32) && occupant(single, col as i32).is_none() {
+                    moves.push((single as usize, col));
+
+                    let double = row as i32 + forward * 2;
+                    if row as i32 == start_row && !piece.has_moved && occupant(double, col as i32).is_none() {
+                        moves.push((double as usize, col));
+                    }
+                }
+
+                for dc in [-1, 1] {
+                    let (r, c) = (row as i32 + forward, col as i32 + dc);
+                    if !in_bounds(r, c) { continue; }
+                    if let Some(target) = occupant(r, c) {
+                        if target.color != piece.color {
+                            moves.push((r as usize, c as usize));
+                        }
+                    } else if en_passant_target == Some((r as usize, c as usize)) {
+                        moves.push((r as usize, c as usize));
+                    }
+                }
+            }
+        }
+
+        moves
     }
 }
 
 struct WindowManager {
     canvas: WindowCanvas,
-    texture_creator: TextureCreator<WindowContext>,
 }
 
 impl WindowManager {
-    fn new(width: u32, height: u32, sdl_context: &sdl2::Sdl) -> Self {
+    fn new(width: u32, height: u32, title: &str, sdl_context: &sdl2::Sdl) -> Self {
         let video = sdl_context.video().expect("Failed to create video");
-        let window = video.window("Chess", width, height)
+        let window = video.window(title, width, height)
             .position_centered()
             .allow_highdpi()
             .build()
@@ -174,74 +495,292 @@ impl WindowManager {
         let canvas = window.into_canvas()
             .accelerated()
             .present_vsync()
+            .target_texture()
             .build()
             .expect("Failed to create canvas");
 
-        let texture_creator = canvas.texture_creator();
-
-        WindowManager {
-            canvas,
-            texture_creator,
-        }
+        WindowManager { canvas }
     }
 }
 
 
 struct Game<'a> {
-    window_manager: WindowManager,
+    // Borrowed rather than owned: `board`'s textures borrow `texture_creator`, so the
+    // creator (and the window it comes from) has to outlive `Game` from a scope above it
+    // instead of living inside it, or the two would be sibling fields referencing each other.
+    window_manager: &'a mut WindowManager,
+    // Kept around (rather than just consumed by the initial `Board::new`) so a finished
+    // game can be replaced with a fresh `Board` instead of replaying its checkmated position.
+    texture_creator: &'a TextureCreator<WindowContext>,
     board: Board<'a>,
     is_running: bool,
     events: EventPump,
+    state: GameState,
+    white_player: PlayerKind,
+    black_player: PlayerKind,
+    result: Option<GameResult>,
+    width: u32,
+    height: u32,
 }
 
-impl Game<'_> {
-    pub fn new() -> Self {
-        let sdl = sdl2::init().expect("Failed to initialize SDL");
-        let window_manager = WindowManager::new(800, 800, &sdl);
-        let board = Board::new(&window_manager.texture_creator);
-        let events = sdl.event_pump().expect("Failed to create event pump");
+impl<'a> Game<'a> {
+    fn new(
+        window_manager: &'a mut WindowManager,
+        texture_creator: &'a TextureCreator<WindowContext>,
+        sdl_context: &sdl2::Sdl,
+        width: u32,
+        height: u32,
+        initial_state: GameState,
+    ) -> Self {
+        let tile_size = std::cmp::min(width, height) as i32 / BOARD_SIZE;
+        let board = Board::new(texture_creator, tile_size);
+        let events = sdl_context.event_pump().expect("Failed to create event pump");
 
         Game {
             window_manager,
+            texture_creator,
             board,
             is_running: false,
             events,
+            state: initial_state,
+            white_player: PlayerKind::Human,
+            black_player: PlayerKind::Ai,
+            result: None,
+            width,
+            height,
         }
     }
 
+    /// Replaces `board` with a fresh starting position and clears the previous result,
+    /// so a new game doesn't inherit the checkmated/stalemated position it ended on.
+    fn reset_board(&mut self) {
+        let tile_size = std::cmp::min(self.width, self.height) as i32 / BOARD_SIZE;
+        self.board = Board::new(self.texture_creator, tile_size);
+        self.result = None;
+    }
+
+
     pub fn run(&mut self) {
         self.is_running = true;
+        let mut last_instant = std::time::Instant::now();
+        let mut accumulator: u64 = 0;
+
         while self.is_running {
             self.process_events();
-            self.render();
 
-            // Cap the fps at 60
-            std::thread::sleep(std::time::Duration::from_millis(16));
+            let now = std::time::Instant::now();
+            accumulator += (now - last_instant).as_nanos() as u64;
+            last_instant = now;
+            accumulator = accumulator.min(MAX_ACCUMULATOR_NS);
+
+            while accumulator >= NS_PER_FRAME {
+                self.update();
+                accumulator -= NS_PER_FRAME;
+            }
+
+            let interpolation = accumulator as f64 / NS_PER_FRAME as f64;
+            self.render(interpolation);
         }
     }
 
     fn process_events(&mut self) {
-        for event in self.events.poll_iter() {
+        let events: Vec<Event> = self.events.poll_iter().collect();
+        for event in events {
             match event {
                 Event::Quit { .. } => self.is_running = false,
+                Event::KeyDown { keycode: Some(sdl2::keyboard::Keycode::Escape), .. } => {
+                    self.state = match self.state {
+                        GameState::Playing => GameState::Paused,
+                        GameState::Paused => GameState::Playing,
+                        other => other,
+                    };
+                }
+                Event::MouseButtonDown { mouse_btn: sdl2::mouse::MouseButton::Left, x, y, .. } => {
+                    self.handle_click(x, y);
+                }
                 _ => {}
             }
         }
     }
 
-    fn render(&mut self) {
-        let mut canvas = &mut self.window_manager.canvas;
+    fn handle_click(&mut self, x: i32, y: i32) {
+        match self.state {
+            GameState::MainMenu => {
+                let layout = MenuLayout::new(self.width, self.height);
+                if layout.white_toggle.contains_point((x, y)) {
+                    self.white_player = self.white_player.toggled();
+                } else if layout.black_toggle.contains_point((x, y)) {
+                    self.black_player = self.black_player.toggled();
+                } else if layout.start_button.contains_point((x, y)) {
+                    self.state = GameState::Playing;
+                }
+            }
+            GameState::Playing => {
+                self.board.handle_click(x, y);
+            }
+            GameState::GameOver => {
+                self.reset_board();
+                self.state = GameState::MainMenu;
+            }
+            GameState::Paused => {}
+        }
+    }
+
+    /// Advances game logic by one fixed timestep. Will drive animations and AI timers.
+    fn update(&mut self) {
+        if let GameState::Playing = self.state {
+            let side_to_move = self.board.side_to_move;
+            if self.board.has_no_legal_moves(side_to_move) {
+                self.result = Some(if self.board.in_check(side_to_move) {
+                    match side_to_move {
+                        PieceColor::White => GameResult::BlackWins,
+                        PieceColor::Black => GameResult::WhiteWins,
+                    }
+                } else {
+                    GameResult::Stalemate
+                });
+                self.state = GameState::GameOver;
+            }
+        }
+    }
 
+    fn render(&mut self, _interpolation: f64) {
+        let state = self.state;
+        let layout = MenuLayout::new(self.width, self.height);
+        let banner_rect = result_banner_rect(self.width, self.height);
+        let window_rect = Rect::new(0, 0, self.width, self.height);
+        let (white_player, black_player, result) = (self.white_player, self.black_player, self.result);
+
+        let canvas = &mut self.window_manager.canvas;
         canvas.set_draw_color(Color::BLACK);
         canvas.clear();
 
-        self.board.render(&mut canvas);
+        match state {
+            GameState::MainMenu => Self::render_menu(canvas, &layout, white_player, black_player),
+            GameState::Playing => self.board.render(canvas),
+            GameState::Paused => {
+                self.board.render(canvas);
+                canvas.set_draw_color(Color::RGBA(0, 0, 0, 128));
+                canvas.fill_rect(window_rect).expect("Failed to fill rect");
+            }
+            GameState::GameOver => {
+                self.board.render(canvas);
+                Self::render_result_banner(canvas, banner_rect, result);
+            }
+        }
 
         canvas.present();
     }
+
+    fn render_menu(canvas: &mut WindowCanvas, layout: &MenuLayout, white_player: PlayerKind, black_player: PlayerKind) {
+        let toggle_color = |kind: PlayerKind| if kind == PlayerKind::Human { Color::RGB(137, 196, 244) } else { Color::RGB(244, 137, 137) };
+
+        canvas.set_draw_color(toggle_color(white_player));
+        canvas.fill_rect(layout.white_toggle).expect("Failed to fill rect");
+
+        canvas.set_draw_color(toggle_color(black_player));
+        canvas.fill_rect(layout.black_toggle).expect("Failed to fill rect");
+
+        canvas.set_draw_color(Color::RGB(111, 189, 111));
+        canvas.fill_rect(layout.start_button).expect("Failed to fill rect");
+    }
+
+    fn render_result_banner(canvas: &mut WindowCanvas, banner_rect: Rect, result: Option<GameResult>) {
+        let color = match result {
+            Some(GameResult::WhiteWins) => Color::RGB(234, 221, 202),
+            Some(GameResult::BlackWins) => Color::RGB(111, 78, 55),
+            Some(GameResult::Stalemate) | None => Color::RGB(150, 150, 150),
+        };
+        canvas.set_draw_color(color);
+        canvas.fill_rect(banner_rect).expect("Failed to fill rect");
+    }
+}
+
+/// Positions of the main menu's clickable regions, scaled to the window size.
+struct MenuLayout {
+    white_toggle: Rect,
+    black_toggle: Rect,
+    start_button: Rect,
+}
+
+impl MenuLayout {
+    fn new(width: u32, height: u32) -> Self {
+        let x = width as i32 / 2 - 150;
+        let top = height as i32 * 5 / 16;
+        MenuLayout {
+            white_toggle: Rect::new(x, top, 300, 60),
+            black_toggle: Rect::new(x, top + 90, 300, 60),
+            start_button: Rect::new(x, top + 210, 300, 60),
+        }
+    }
+}
+
+fn result_banner_rect(width: u32, height: u32) -> Rect {
+    Rect::new(width as i32 / 2 - 200, height as i32 / 2 - 50, 400, 100)
+}
+
+/// Builds a `Game` with a configurable window resolution, title, and entry state,
+/// instead of the 800x800 "Chess" main menu the constants used to bake in.
+/// `build` does not open the window: the caller creates the `WindowManager` from the
+/// builder's `width`, `height` and `title`, and `build` itself uses only the resolution
+/// (for board scaling) and the entry state.
+struct GameBuilder {
+    width: u32,
+    height: u32,
+    title: String,
+    initial_state: GameState,
+}
+
+impl Default for GameBuilder {
+    fn default() -> Self {
+        GameBuilder {
+            width: DEFAULT_WINDOW_SIZE,
+            height: DEFAULT_WINDOW_SIZE,
+            title: DEFAULT_TITLE.to_string(),
+            initial_state: GameState::MainMenu,
+        }
+    }
+}
+
+impl GameBuilder {
+    fn with_resolution(mut self, width: u32, height: u32) -> Self {
+        self.width = width;
+        self.height = height;
+        self
+    }
+
+    fn with_title(mut self, title: &str) -> Self {
+        self.title = title.to_string();
+        self
+    }
+
+    // Unused by `main`'s default MainMenu entry point today, but part of the builder's
+    // public surface for embedding the crate with a different starting state (e.g. tests).
+    #[allow(dead_code)]
+    fn with_initial_state(mut self, initial_state: GameState) -> Self {
+        self.initial_state = initial_state;
+        self
+    }
+
+    fn build<'a>(
+        self,
+        window_manager: &'a mut WindowManager,
+        texture_creator: &'a TextureCreator<WindowContext>,
+        sdl_context: &sdl2::Sdl,
+    ) -> Game<'a> {
+        Game::new(window_manager, texture_creator, sdl_context, self.width, self.height, self.initial_state)
+    }
 }
 
 fn main() {
-    let mut game = Game::new();
+    let builder = GameBuilder::default()
+        .with_resolution(1024, 1024)
+        .with_title("Rust Chess");
+
+    let sdl_context = sdl2::init().expect("Failed to initialize SDL");
+    let mut window_manager = WindowManager::new(builder.width, builder.height, &builder.title, &sdl_context);
+    let texture_creator = window_manager.canvas.texture_creator();
+
+    let mut game = builder.build(&mut window_manager, &texture_creator, &sdl_context);
     game.run();
 }